@@ -1,7 +1,7 @@
-use std::time::Duration;
+use std::{iter::Peekable, time::Duration};
 
 pub trait Backoff {
-    fn delay(&self, attempt: u32) -> Duration;
+    fn delay(&mut self, attempt: u32) -> Duration;
     fn base(&self) -> Duration;
 }
 
@@ -17,7 +17,7 @@ impl Backoff for Fixed {
     fn base(&self) -> Duration {
         self.0
     }
-    fn delay(&self, _attempt: u32) -> Duration {
+    fn delay(&mut self, _attempt: u32) -> Duration {
         self.0
     }
 }
@@ -34,24 +34,74 @@ impl Backoff for Linear {
     fn base(&self) -> Duration {
         self.0
     }
-    fn delay(&self, attempt: u32) -> Duration {
+    fn delay(&mut self, attempt: u32) -> Duration {
         self.0 * attempt
     }
 }
 
-pub struct Exponential(Duration);
+pub struct Exponential {
+    base: Duration,
+    factor: f32,
+}
 
 impl Exponential {
     pub fn base(dur: Duration) -> Self {
-        Self(dur)
+        Self {
+            base: dur,
+            factor: 2.0,
+        }
+    }
+    /// Sets the growth factor applied to the base delay on each attempt. Defaults to `2.0`
+    /// (doubling). Values closer to `1.0` grow more slowly, while larger values grow more steeply.
+    pub fn factor(mut self, factor: f32) -> Self {
+        self.factor = factor;
+        self
     }
 }
 
 impl Backoff for Exponential {
     fn base(&self) -> Duration {
-        self.0
+        self.base
+    }
+    fn delay(&mut self, attempt: u32) -> Duration {
+        let secs = self.base.as_secs_f64() * (self.factor as f64).powi(attempt as i32);
+        Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+    }
+}
+
+/// A [`Backoff`] adapter that yields delays from a caller-provided iterator instead of computing
+/// them from a formula, letting callers supply an arbitrary precomputed schedule.
+pub struct Schedule<I: Iterator<Item = Duration>> {
+    iter: Peekable<I>,
+    base: Duration,
+    last: Duration,
+}
+
+impl<I: Iterator<Item = Duration>> Schedule<I> {
+    pub fn new(iter: I) -> Self {
+        let mut iter = iter.peekable();
+        let base = iter.peek().copied().unwrap_or(Duration::from_secs(0));
+        Self {
+            iter,
+            base,
+            last: base,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Duration>> Backoff for Schedule<I> {
+    /// Unlike the other `Backoff` impls' `base()`, which is simply the constructor's fixed
+    /// argument, this returns the first value the iterator yields (peeked at construction time)
+    /// so it stays a stable, immutable value rather than tracking `delay`'s moving position.
+    fn base(&self) -> Duration {
+        self.base
     }
-    fn delay(&self, attempt: u32) -> Duration {
-        self.0 * 2u32.pow(attempt)
+    /// Returns the iterator's next value, ignoring `attempt`. Once the iterator is exhausted,
+    /// keeps returning the last value it yielded.
+    fn delay(&mut self, _attempt: u32) -> Duration {
+        if let Some(next) = self.iter.next() {
+            self.last = next;
+        }
+        self.last
     }
 }
@@ -53,3 +53,25 @@ impl Jitter for Decorrelated {
         max.map_or_else(|| next, |max| max.min(next))
     }
 }
+
+pub struct Proportional {
+    factor: f64,
+}
+
+impl Proportional {
+    pub fn factor(factor: f64) -> Self {
+        Self { factor }
+    }
+}
+
+impl Jitter for Proportional {
+    fn jitter(&mut self, delay: Duration, max: Option<Duration>) -> Duration {
+        let capped = max.map_or(delay, |max| max.min(delay));
+        let spread = Duration::try_from_secs_f64(capped.as_secs_f64() * self.factor)
+            .unwrap_or(Duration::MAX);
+        let low = capped.saturating_sub(spread);
+        let high = capped.saturating_add(spread);
+        let next = rand::thread_rng().gen_range(low..=high);
+        max.map_or(next, |max| max.min(next))
+    }
+}
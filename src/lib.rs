@@ -5,11 +5,18 @@ compile_error!("At least on of 'tokio' or 'async-std' feature must be enabled");
 
 pub mod backoff;
 pub mod jitter;
+mod retryable;
+pub mod sleeper;
 
-use std::{marker::PhantomData, time::Duration};
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
 
-pub use backoff::{Backoff, Exponential, Fixed, Linear};
-pub use jitter::{Decorrelated, Equal, Full, Jitter, NoJitter};
+pub use backoff::{Backoff, Exponential, Fixed, Linear, Schedule};
+pub use jitter::{Decorrelated, Equal, Full, Jitter, NoJitter, Proportional};
+pub use retryable::{BlockingRetryable, Retryable};
+pub use sleeper::{DefaultSleeper, Sleeper, TestSleeper};
 
 /// Continues retrying the provided future until a successful result is obtained.
 ///
@@ -69,6 +76,8 @@ where
         backoff: Fixed::base(Duration::from_secs(0)),
         jitterable: jitter::NoJitter,
         max: None,
+        deadline: None,
+        sleeper: DefaultSleeper,
         before_attempt: None,
         after_attempt: None,
         _phantom: PhantomData,
@@ -76,27 +85,31 @@ where
 }
 
 /// Not meant to be constructed directly. Use `mulligan::until_ok()` or `mulligan::until(...)` to construct.
-pub struct Mulligan<T, E, Cond, Jit, Back>
+pub struct Mulligan<T, E, Cond, Jit, Back, Slp = DefaultSleeper>
 where
     Cond: Fn(&Result<T, E>) -> bool,
     Jit: jitter::Jitter,
     Back: backoff::Backoff,
+    Slp: Sleeper,
 {
     stop_after: Option<u32>,
     until: Cond,
     backoff: Back,
     jitterable: Jit,
     max: Option<Duration>,
+    deadline: Option<Duration>,
+    sleeper: Slp,
     before_attempt: Option<Box<dyn Fn(u32) + Send + Sync + 'static>>,
     after_attempt: Option<Box<dyn Fn(&Result<T, E>, u32) + Send + Sync + 'static>>,
     _phantom: PhantomData<(T, E)>,
 }
 
-impl<T, E, Cond, Jit, Back> Mulligan<T, E, Cond, Jit, Back>
+impl<T, E, Cond, Jit, Back, Slp> Mulligan<T, E, Cond, Jit, Back, Slp>
 where
     Cond: Fn(&Result<T, E>) -> bool,
     Jit: jitter::Jitter,
     Back: backoff::Backoff,
+    Slp: Sleeper,
 {
     /// Retries a provided future until the stopping condition has been met. The default settings will
     /// retry forever with no delay between attempts. Backoff, Maximum Backoff, and Maximum Attempts
@@ -122,6 +135,7 @@ where
     where
         F: AsyncFnMut() -> Result<T, E> + 'static,
     {
+        let start = Instant::now();
         let mut attempt: u32 = 0;
         loop {
             if let Some(before_attempt) = &self.before_attempt {
@@ -136,58 +150,16 @@ where
 
             let delay = self.calculate_delay(attempt);
 
-            Self::sleep(delay).await;
-
-            if let Some(after_attempt) = &self.after_attempt {
-                after_attempt(&res, attempt);
-            }
-
-            attempt += 1;
-        }
-    }
-    /// Retries a provided function until the stopping condition has been met. The default settings will
-    /// retry forever with no delay between attempts. Backoff, Maximum Backoff, and Maximum Attempts
-    /// can be configured with the other methods on the struct.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use std::time::Duration;
-    ///
-    /// fn this_errors(msg: &str) -> std::io::Result<()> {
-    ///     println!("{msg}");
-    ///     Err(std::io::Error::other("uh oh!"))
-    /// }
-    ///
-    /// # async fn example() {
-    /// mulligan::until_ok()
-    ///     .stop_after(2)
-    ///     .execute_sync(move || { this_errors("hello") });
-    /// # }
-    /// ```
-    pub fn execute_sync<F>(mut self, mut f: F) -> Result<T, E>
-    where
-        F: FnMut() -> Result<T, E>,
-    {
-        let mut attempt: u32 = 0;
-        loop {
-            if let Some(before_attempt) = &self.before_attempt {
-                before_attempt(attempt);
-            }
-
-            let res = f();
-
-            if self.stop_after.is_some_and(|max| attempt >= max) | (self.until)(&res) {
+            let Some(delay) = self.clamp_to_deadline(start, delay) else {
                 return res;
-            }
-
-            let delay = self.calculate_delay(attempt);
+            };
 
-            std::thread::sleep(delay);
+            self.sleeper.sleep(delay).await;
 
             if let Some(after_attempt) = &self.after_attempt {
                 after_attempt(&res, attempt);
             }
+
             attempt += 1;
         }
     }
@@ -226,8 +198,23 @@ where
         let delay = self.backoff.delay(attempt);
         self.jitterable.jitter(delay, self.max)
     }
+    /// Clamps `delay` to whatever remains of the deadline budget, if one is set. Returns `None`
+    /// once the budget is already exhausted, signalling that retrying should stop.
+    fn clamp_to_deadline(&self, start: Instant, delay: Duration) -> Option<Duration> {
+        match self.deadline {
+            None => Some(delay),
+            Some(budget) => {
+                let elapsed = start.elapsed();
+                if elapsed >= budget {
+                    None
+                } else {
+                    Some(delay.min(budget - elapsed))
+                }
+            }
+        }
+    }
     /// Adjust the backoff by the provided jitter strategy
-    pub fn jitter<J>(self, jitter: J) -> Mulligan<T, E, Cond, J, Back>
+    pub fn jitter<J>(self, jitter: J) -> Mulligan<T, E, Cond, J, Back, Slp>
     where
         J: jitter::Jitter,
     {
@@ -237,32 +224,38 @@ where
             backoff: self.backoff,
             jitterable: jitter,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Adjust the calculated backoff by choosing a random delay between 0 and the backoff value
-    pub fn full_jitter(self) -> Mulligan<T, E, Cond, jitter::Full, Back> {
+    pub fn full_jitter(self) -> Mulligan<T, E, Cond, jitter::Full, Back, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: self.backoff,
             jitterable: jitter::Full,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Adjust the calculated backoff by choosing a random delay between backoff / 2 and the backoff value
-    pub fn equal_jitter(self) -> Mulligan<T, E, Cond, jitter::Equal, Back> {
+    pub fn equal_jitter(self) -> Mulligan<T, E, Cond, jitter::Equal, Back, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: self.backoff,
             jitterable: jitter::Equal,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
@@ -272,20 +265,42 @@ where
     pub fn decorrelated_jitter(
         self,
         base: Duration,
-    ) -> Mulligan<T, E, Cond, jitter::Decorrelated, Back> {
+    ) -> Mulligan<T, E, Cond, jitter::Decorrelated, Back, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: self.backoff,
             jitterable: jitter::Decorrelated::base(base),
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
+            before_attempt: self.before_attempt,
+            after_attempt: self.after_attempt,
+            _phantom: PhantomData,
+        }
+    }
+    /// Adjust the calculated backoff by choosing a random delay between `backoff - backoff * factor`
+    /// and `backoff + backoff * factor`, preserving the intended backoff curve instead of collapsing
+    /// it the way `full_jitter`/`equal_jitter` can.
+    pub fn proportional_jitter(
+        self,
+        factor: f64,
+    ) -> Mulligan<T, E, Cond, jitter::Proportional, Back, Slp> {
+        Mulligan {
+            stop_after: self.stop_after,
+            until: self.until,
+            backoff: self.backoff,
+            jitterable: jitter::Proportional::factor(factor),
+            max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Delay by the calculated backoff strategy.
-    pub fn backoff<B>(self, backoff: B) -> Mulligan<T, E, Cond, Jit, B>
+    pub fn backoff<B>(self, backoff: B) -> Mulligan<T, E, Cond, Jit, B, Slp>
     where
         B: Backoff,
     {
@@ -295,45 +310,73 @@ where
             backoff,
             jitterable: self.jitterable,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Wait a fixed amount of time between each retry.
-    pub fn fixed(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Fixed> {
+    pub fn fixed(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Fixed, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: Fixed::base(dur),
             jitterable: self.jitterable,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Wait a growing amount of time between each retry `base * attempt`
-    pub fn linear(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Linear> {
+    pub fn linear(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Linear, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: Linear::base(dur),
             jitterable: self.jitterable,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
         }
     }
     /// Wait a growing amount of time between each retry `base * 2.pow(attempt)`
-    pub fn exponential(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Exponential> {
+    pub fn exponential(self, dur: Duration) -> Mulligan<T, E, Cond, Jit, Exponential, Slp> {
         Mulligan {
             stop_after: self.stop_after,
             until: self.until,
             backoff: Exponential::base(dur),
             jitterable: self.jitterable,
             max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
+            before_attempt: self.before_attempt,
+            after_attempt: self.after_attempt,
+            _phantom: PhantomData,
+        }
+    }
+    /// Wait according to a caller-provided sequence of delays, e.g.
+    /// `vec![Duration::from_millis(100), Duration::from_secs(1)].into_iter()`. Once the iterator
+    /// is exhausted, the last value it yielded is reused for subsequent attempts.
+    pub fn schedule<I>(self, iter: I) -> Mulligan<T, E, Cond, Jit, Schedule<I>, Slp>
+    where
+        I: Iterator<Item = Duration>,
+    {
+        Mulligan {
+            stop_after: self.stop_after,
+            until: self.until,
+            backoff: Schedule::new(iter),
+            jitterable: self.jitterable,
+            max: self.max,
+            deadline: self.deadline,
+            sleeper: self.sleeper,
             before_attempt: self.before_attempt,
             after_attempt: self.after_attempt,
             _phantom: PhantomData,
@@ -344,13 +387,96 @@ where
         self.max = Some(dur);
         self
     }
-
-    #[cfg(feature = "tokio")]
-    async fn sleep(dur: Duration) {
-        tokio::time::sleep(dur).await;
+    /// Bound the total wall-clock time spent retrying, regardless of `stop_after`. The budget
+    /// starts ticking at the beginning of `execute`/`execute_sync`. Once it would be exceeded by
+    /// the next sleep, the sleep is clamped to whatever time remains, and once no time remains
+    /// at all the last `Result` is returned immediately.
+    pub fn deadline(mut self, dur: Duration) -> Self {
+        self.deadline = Some(dur);
+        self
+    }
+    /// Overrides how `execute` waits between attempts, replacing the runtime-specific default.
+    /// Useful for deterministic tests (see [`sleeper::TestSleeper`]) or for targets without
+    /// `tokio`/`async-std`, such as WASM. Only `execute` reads this override; `execute_sync`
+    /// always blocks via `std::thread::sleep` and is therefore only available on the default
+    /// (unconfigured) sleeper, since it has no async runtime to drive a custom `Sleeper` on.
+    pub fn sleeper<S>(self, sleeper: S) -> Mulligan<T, E, Cond, Jit, Back, S>
+    where
+        S: Sleeper,
+    {
+        Mulligan {
+            stop_after: self.stop_after,
+            until: self.until,
+            backoff: self.backoff,
+            jitterable: self.jitterable,
+            max: self.max,
+            deadline: self.deadline,
+            sleeper,
+            before_attempt: self.before_attempt,
+            after_attempt: self.after_attempt,
+            _phantom: PhantomData,
+        }
     }
-    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
-    async fn sleep(dur: Duration) {
-        async_std::future::sleep(dur).await;
+}
+
+impl<T, E, Cond, Jit, Back> Mulligan<T, E, Cond, Jit, Back, DefaultSleeper>
+where
+    Cond: Fn(&Result<T, E>) -> bool,
+    Jit: jitter::Jitter,
+    Back: backoff::Backoff,
+{
+    /// Retries a provided function until the stopping condition has been met. The default settings will
+    /// retry forever with no delay between attempts. Backoff, Maximum Backoff, and Maximum Attempts
+    /// can be configured with the other methods on the struct.
+    ///
+    /// This always sleeps via `std::thread::sleep`, so it is only available when no custom
+    /// [`sleeper`] has been configured; use `execute` if you need a pluggable `Sleeper`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// fn this_errors(msg: &str) -> std::io::Result<()> {
+    ///     println!("{msg}");
+    ///     Err(std::io::Error::other("uh oh!"))
+    /// }
+    ///
+    /// # async fn example() {
+    /// mulligan::until_ok()
+    ///     .stop_after(2)
+    ///     .execute_sync(move || { this_errors("hello") });
+    /// # }
+    /// ```
+    pub fn execute_sync<F>(mut self, mut f: F) -> Result<T, E>
+    where
+        F: FnMut() -> Result<T, E>,
+    {
+        let start = Instant::now();
+        let mut attempt: u32 = 0;
+        loop {
+            if let Some(before_attempt) = &self.before_attempt {
+                before_attempt(attempt);
+            }
+
+            let res = f();
+
+            if self.stop_after.is_some_and(|max| attempt >= max) | (self.until)(&res) {
+                return res;
+            }
+
+            let delay = self.calculate_delay(attempt);
+
+            let Some(delay) = self.clamp_to_deadline(start, delay) else {
+                return res;
+            };
+
+            std::thread::sleep(delay);
+
+            if let Some(after_attempt) = &self.after_attempt {
+                after_attempt(&res, attempt);
+            }
+            attempt += 1;
+        }
     }
 }
@@ -0,0 +1,58 @@
+use std::future::Future;
+
+use crate::{DefaultSleeper, Mulligan, Sleeper, backoff::Backoff, jitter::Jitter};
+
+/// Extension trait that lets an async operation retry itself fluently:
+/// `(|| async { fetch().await }).retry(policy).await` instead of `policy.execute(fetch)`.
+pub trait Retryable<T, E, Cond, Jit, Back, Slp = DefaultSleeper>
+where
+    Cond: Fn(&Result<T, E>) -> bool,
+    Jit: Jitter,
+    Back: Backoff,
+    Slp: Sleeper,
+{
+    fn retry(
+        self,
+        policy: Mulligan<T, E, Cond, Jit, Back, Slp>,
+    ) -> impl Future<Output = Result<T, E>>;
+}
+
+impl<F, T, E, Cond, Jit, Back, Slp> Retryable<T, E, Cond, Jit, Back, Slp> for F
+where
+    F: AsyncFnMut() -> Result<T, E> + 'static,
+    Cond: Fn(&Result<T, E>) -> bool,
+    Jit: Jitter,
+    Back: Backoff,
+    Slp: Sleeper,
+{
+    async fn retry(self, policy: Mulligan<T, E, Cond, Jit, Back, Slp>) -> Result<T, E> {
+        policy.execute(self).await
+    }
+}
+
+/// Extension trait that lets a synchronous operation retry itself fluently:
+/// `fetch.retry(policy)` instead of `policy.execute_sync(fetch)`.
+///
+/// This maps to `execute_sync`, which always blocks via `std::thread::sleep`, so (unlike
+/// [`Retryable`]) it is only implemented for the default, unconfigured `Sleeper` — there is no
+/// async runtime here to drive a custom one on.
+pub trait BlockingRetryable<T, E, Cond, Jit, Back>
+where
+    Cond: Fn(&Result<T, E>) -> bool,
+    Jit: Jitter,
+    Back: Backoff,
+{
+    fn retry(self, policy: Mulligan<T, E, Cond, Jit, Back, DefaultSleeper>) -> Result<T, E>;
+}
+
+impl<F, T, E, Cond, Jit, Back> BlockingRetryable<T, E, Cond, Jit, Back> for F
+where
+    F: FnMut() -> Result<T, E>,
+    Cond: Fn(&Result<T, E>) -> bool,
+    Jit: Jitter,
+    Back: Backoff,
+{
+    fn retry(self, policy: Mulligan<T, E, Cond, Jit, Back, DefaultSleeper>) -> Result<T, E> {
+        policy.execute_sync(self)
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Abstracts the act of waiting between retries so the core retry loop doesn't depend on any
+/// particular async runtime. The default implementation delegates to whichever of `tokio` /
+/// `async-std` is enabled; swap in [`TestSleeper`] for deterministic tests, or a custom
+/// implementation (e.g. backed by `gloo-timers`) to target WASM.
+pub trait Sleeper {
+    fn sleep(&self, dur: Duration) -> impl Future<Output = ()> + Send;
+}
+
+/// The runtime-specific [`Sleeper`] used when none is configured explicitly.
+pub struct DefaultSleeper;
+
+impl Sleeper for DefaultSleeper {
+    #[cfg(feature = "tokio")]
+    async fn sleep(&self, dur: Duration) {
+        tokio::time::sleep(dur).await;
+    }
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    async fn sleep(&self, dur: Duration) {
+        async_std::future::sleep(dur).await;
+    }
+}
+
+/// A [`Sleeper`] for tests: records every requested duration instead of actually waiting, so
+/// the exact backoff sequence (e.g. exponential growth, full-jitter bounds) can be asserted
+/// without wall-clock delays. The recorded durations are held behind an `Arc`, so clone the
+/// sleeper before handing it to `.sleeper(...)` (which consumes it) to keep a handle you can
+/// call `requested()` on afterward.
+#[derive(Default, Clone)]
+pub struct TestSleeper {
+    requested: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl TestSleeper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns the durations requested so far, in order.
+    pub fn requested(&self) -> Vec<Duration> {
+        self.requested.lock().unwrap().clone()
+    }
+}
+
+impl Sleeper for TestSleeper {
+    async fn sleep(&self, dur: Duration) {
+        self.requested.lock().unwrap().push(dur);
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use super::*;
+    use crate::until_ok;
+
+    #[tokio::test]
+    async fn records_the_exact_backoff_sequence() {
+        let sleeper = TestSleeper::new();
+        let handle = sleeper.clone();
+        let mut attempt = 0u32;
+
+        let _: Result<(), ()> = until_ok()
+            .stop_after(3)
+            .exponential(Duration::from_millis(100))
+            .sleeper(sleeper)
+            .execute(move || {
+                attempt += 1;
+                let done = attempt > 3;
+                async move { if done { Ok(()) } else { Err(()) } }
+            })
+            .await;
+
+        assert_eq!(
+            handle.requested(),
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+            ]
+        );
+    }
+}